@@ -1,4 +1,5 @@
 use async_std::future::Future;
+use async_trait::async_trait;
 
 use crate::utils::BoxFuture;
 use crate::{response::IntoResponse, Request, Response};
@@ -6,10 +7,31 @@ use crate::{response::IntoResponse, Request, Response};
 /// An HTTP request handler.
 ///
 /// This trait is automatically implemented for `Fn` types, and so is rarely implemented
-/// directly by Tide users.
+/// directly by Tide users. It is built on [`async_trait`], so an endpoint that needs to hold
+/// its own state (a database handle, a template cache) can implement it by hand with a plain
+/// `async fn call`:
+///
+/// ```no_run
+/// # use tide::{Request, Response};
+/// # use tide::response::IntoResponse;
+/// struct Greeter {
+///     greeting: String,
+/// }
+///
+/// #[async_trait::async_trait]
+/// impl tide::Endpoint<()> for Greeter {
+///     async fn call(&self, _req: Request<()>) -> Response {
+///         self.greeting.clone().into_response()
+///     }
+/// }
+/// ```
 ///
 /// In practice, endpoints are functions that take a `Request<State>` as an argument and
-/// return a type `T` that implements [`IntoResponse`].
+/// return a type `T` that implements [`IntoResponse`]. Besides the bare response body, `T` may
+/// be a tuple such as `(StatusCode, T)` or `(StatusCode, Headers, T)` to set the status and
+/// headers explicitly, an `Option<T>` (`None` becomes `404 Not Found`), or a
+/// [`Json`](crate::responder::Json) / [`Html`](crate::responder::Html) wrapper to control the
+/// `Content-Type` of the body — these all funnel through the same `into_response()` call below.
 ///
 /// # Examples
 ///
@@ -45,26 +67,62 @@ use crate::{response::IntoResponse, Request, Response};
 /// ```
 ///
 /// Tide routes will also accept endpoints with `Fn` signatures of this form, but using the `async` keyword has better ergonomics.
+///
+/// Note that `#[async_trait]` works by boxing the future returned from `call`, so this does not
+/// by itself make dispatch any cheaper than it was before — it's the same `Box::pin` the `Fn`
+/// blanket impl below always paid, just performed by the macro instead of by hand. Avoiding that
+/// allocation on routes where the endpoint type is known statically needs changes to route
+/// storage itself, which holds every endpoint as a boxed [`DynEndpoint`] closure.
+///
+/// Endpoints may also be fallible, returning a `Result<T, E>` where `T: IntoResponse` and
+/// `E: Into<tide::Error>`. This lets handlers use `?` to short-circuit on failure; the error is
+/// turned into a response using its status code (see [`Error`](crate::Error)):
+///
+/// ```no_run
+/// async fn hello(_cx: tide::Request<()>) -> Result<String, std::io::Error> {
+///     Ok(String::from("hello"))
+/// }
+///
+/// fn main() {
+///     let mut app = tide::Server::new();
+///     app.at("/hello").get(hello);
+/// }
+/// ```
+#[async_trait]
 pub trait Endpoint<State>: Send + Sync + 'static {
-    /// The async result of `call`.
-    type Fut: Future<Output = Response> + Send + 'static;
-
     /// Invoke the endpoint within the given context
-    fn call(&self, cx: Request<State>) -> Self::Fut;
+    async fn call(&self, cx: Request<State>) -> Response;
 }
 
+/// The type-erased representation of an endpoint, as stored in route tables elsewhere in the
+/// crate. This keeps the pre-existing `Fn(Request<State>) -> BoxFuture<'static, Response>` shape
+/// rather than becoming `dyn Endpoint<State>`, so reshaping `Endpoint` to `#[async_trait]` above
+/// doesn't change the call convention (`(endpoint)(req)`) at any existing `DynEndpoint` call
+/// site. Use [`erase`] to go from an `E: Endpoint<State>` to this representation.
 pub(crate) type DynEndpoint<State> =
     dyn (Fn(Request<State>) -> BoxFuture<'static, Response>) + 'static + Send + Sync;
 
-impl<State, F: Send + Sync + 'static, Fut> Endpoint<State> for F
+/// Erase an [`Endpoint`] into the boxed-closure representation used by [`DynEndpoint`].
+pub(crate) fn erase<State: Send + Sync + 'static>(
+    endpoint: impl Endpoint<State>,
+) -> Box<DynEndpoint<State>> {
+    let endpoint = std::sync::Arc::new(endpoint);
+    Box::new(move |cx| {
+        let endpoint = endpoint.clone();
+        Box::pin(async move { endpoint.call(cx).await })
+    })
+}
+
+#[async_trait]
+impl<State, F, Fut> Endpoint<State> for F
 where
-    F: Fn(Request<State>) -> Fut,
+    State: Send + Sync + 'static,
+    F: Fn(Request<State>) -> Fut + Send + Sync + 'static,
     Fut: Future + Send + 'static,
     Fut::Output: IntoResponse,
 {
-    type Fut = BoxFuture<'static, Response>;
-    fn call(&self, cx: Request<State>) -> Self::Fut {
+    async fn call(&self, cx: Request<State>) -> Response {
         let fut = (self)(cx);
-        Box::pin(async move { fut.await.into_response() })
+        fut.await.into_response()
     }
 }
